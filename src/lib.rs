@@ -1,20 +1,75 @@
 //! This crate offers filters for the [Tera](https://github.com/Keats/tera) engine that are centered around text transformations.
 
-use heck::{CamelCase, KebabCase, MixedCase, SnakeCase, TitleCase};
-use std::{collections::HashMap, hash::BuildHasher};
-use tera::{to_value, try_get_value, Result, Tera, Value};
+use heck::{
+    AsKebabCase, AsLowerCamelCase, AsShoutyKebabCase, AsShoutySnakeCase, AsSnakeCase, AsTitleCase,
+    AsTrainCase, AsUpperCamelCase,
+};
+use rand::{rngs::StdRng, Rng, RngCore, SeedableRng};
+use std::{collections::HashMap, fmt::Write, hash::BuildHasher};
+use tera::{to_value, try_get_value, Error, Result, Tera, Value};
 
 /// Registers all available filters for a given `Tera` instance.
 pub fn register_all(tera: &mut Tera) {
+    tera.register_filter("alternating_case", alternating_case);
     tera.register_filter("camel_case", camel_case);
+    tera.register_filter("convert_case", convert_case);
     tera.register_filter("kebab_case", kebab_case);
     tera.register_filter("lower_case", lower_case);
     tera.register_filter("mixed_case", mixed_case);
+    tera.register_filter("quote", quote);
+    tera.register_filter("random_case", random_case);
+    tera.register_filter("shouty_kebab_case", shouty_kebab_case);
+    tera.register_filter("shouty_snake_case", shouty_snake_case);
     tera.register_filter("snake_case", snake_case);
     tera.register_filter("title_case", title_case);
+    tera.register_filter("toggle_case", toggle_case);
+    tera.register_filter("train_case", train_case);
     tera.register_filter("upper_case", upper_case);
 }
 
+/// Lowercases even-indexed letters and uppercases odd-indexed letters, leaving
+/// non-letter characters untouched.
+///
+/// # Example
+///
+/// ```
+/// use tera::{Context, Tera};
+/// use tera_text_filters::alternating_case;
+///
+/// let mut ctx = Context::new();
+/// ctx.insert("i", "some text");
+///
+/// let mut tera = Tera::default();
+/// tera.register_filter("alternating_case", alternating_case);
+///
+/// let i = "{{ i | alternating_case }}";
+/// let rendered = tera.render_str(i, &ctx).unwrap();
+/// assert_eq!(rendered, "sOmE tExT");
+/// ```
+pub fn alternating_case<S: BuildHasher>(
+    value: &Value,
+    _: &HashMap<String, Value, S>,
+) -> Result<Value> {
+    let s = try_get_value!("alternating_case", "value", String, value);
+    let mut letter_index = 0usize;
+    let alternated: String = s
+        .chars()
+        .map(|c| {
+            if !c.is_alphabetic() {
+                return c;
+            }
+            let even = letter_index.is_multiple_of(2);
+            letter_index += 1;
+            if even {
+                c.to_lowercase().next().unwrap_or(c)
+            } else {
+                c.to_uppercase().next().unwrap_or(c)
+            }
+        })
+        .collect();
+    Ok(to_value(&alternated).unwrap())
+}
+
 /// Converts text into CamelCase.
 ///
 /// # Example
@@ -35,7 +90,96 @@ pub fn register_all(tera: &mut Tera) {
 /// ```
 pub fn camel_case<S: BuildHasher>(value: &Value, _: &HashMap<String, Value, S>) -> Result<Value> {
     let s = try_get_value!("camel_case", "value", String, value);
-    Ok(to_value(&s.to_camel_case()).unwrap())
+    let mut out = String::new();
+    write!(out, "{}", AsUpperCamelCase(&s)).unwrap();
+    Ok(to_value(&out).unwrap())
+}
+
+/// Converts text into the case named by the `to` argument.
+///
+/// Supported case names are `"camel"`, `"pascal"`, `"mixed"`, `"snake"`, `"kebab"`,
+/// `"shouty_snake"`, `"screaming_kebab"`, `"title"`, `"train"`, `"lower"`, and `"upper"`.
+/// An unrecognized case name produces an error instead of panicking.
+///
+/// An optional `split` argument enables boundary-aware word segmentation before
+/// re-casing, so that acronym and digit transitions such as `"HTTPServer"` or
+/// `"file2name"` split the way a human would expect instead of heck's naive
+/// per-character splitting.
+///
+/// # Examples
+///
+/// ```
+/// use tera::{Context, Tera};
+/// use tera_text_filters::convert_case;
+///
+/// let mut ctx = Context::new();
+/// ctx.insert("i", "some text");
+///
+/// let mut tera = Tera::default();
+/// tera.register_filter("convert_case", convert_case);
+///
+/// let i = "{{ i | convert_case(to=\"snake\") }}";
+/// let rendered = tera.render_str(i, &ctx).unwrap();
+/// assert_eq!(rendered, "some_text");
+/// ```
+///
+/// With `split=true`, acronym and digit boundaries are segmented before re-casing:
+///
+/// ```
+/// use tera::{Context, Tera};
+/// use tera_text_filters::convert_case;
+///
+/// let mut ctx = Context::new();
+/// ctx.insert("i", "HTTPServer");
+///
+/// let mut tera = Tera::default();
+/// tera.register_filter("convert_case", convert_case);
+///
+/// let i = "{{ i | convert_case(to=\"snake\", split=true) }}";
+/// let rendered = tera.render_str(i, &ctx).unwrap();
+/// assert_eq!(rendered, "http_server");
+/// ```
+pub fn convert_case<S: BuildHasher>(
+    value: &Value,
+    args: &HashMap<String, Value, S>,
+) -> Result<Value> {
+    let s = try_get_value!("convert_case", "value", String, value);
+    let to = match args.get("to") {
+        Some(val) => try_get_value!("convert_case", "to", String, val),
+        None => return Err(Error::msg("Filter `convert_case` expected an arg called `to`")),
+    };
+    let split = match args.get("split") {
+        Some(val) => try_get_value!("convert_case", "split", bool, val),
+        None => false,
+    };
+
+    let s = if split {
+        segment_words(&s).join("_")
+    } else {
+        s
+    };
+
+    let mut converted = String::new();
+    match to.as_str() {
+        "camel" | "pascal" => write!(converted, "{}", AsUpperCamelCase(&s)).unwrap(),
+        "mixed" => write!(converted, "{}", AsLowerCamelCase(&s)).unwrap(),
+        "snake" => write!(converted, "{}", AsSnakeCase(&s)).unwrap(),
+        "kebab" => write!(converted, "{}", AsKebabCase(&s)).unwrap(),
+        "shouty_snake" => write!(converted, "{}", AsShoutySnakeCase(&s)).unwrap(),
+        "screaming_kebab" => write!(converted, "{}", AsShoutyKebabCase(&s)).unwrap(),
+        "title" => write!(converted, "{}", AsTitleCase(&s)).unwrap(),
+        "train" => write!(converted, "{}", AsTrainCase(&s)).unwrap(),
+        "lower" => converted.push_str(&s.to_lowercase()),
+        "upper" => converted.push_str(&s.to_uppercase()),
+        other => {
+            return Err(Error::msg(format!(
+                "Filter `convert_case` received an unknown case name `{}`",
+                other
+            )))
+        }
+    };
+
+    Ok(to_value(&converted).unwrap())
 }
 
 /// Converts text into kebab-case.
@@ -58,7 +202,9 @@ pub fn camel_case<S: BuildHasher>(value: &Value, _: &HashMap<String, Value, S>)
 /// ```
 pub fn kebab_case<S: BuildHasher>(value: &Value, _: &HashMap<String, Value, S>) -> Result<Value> {
     let s = try_get_value!("kebab_case", "value", String, value);
-    Ok(to_value(&s.to_kebab_case()).unwrap())
+    let mut out = String::new();
+    write!(out, "{}", AsKebabCase(&s)).unwrap();
+    Ok(to_value(&out).unwrap())
 }
 
 /// Converts text into lowercase.
@@ -104,7 +250,172 @@ pub fn lower_case<S: BuildHasher>(value: &Value, _: &HashMap<String, Value, S>)
 /// ```
 pub fn mixed_case<S: BuildHasher>(value: &Value, _: &HashMap<String, Value, S>) -> Result<Value> {
     let s = try_get_value!("mixed_case", "value", String, value);
-    Ok(to_value(&s.to_mixed_case()).unwrap())
+    let mut out = String::new();
+    write!(out, "{}", AsLowerCamelCase(&s)).unwrap();
+    Ok(to_value(&out).unwrap())
+}
+
+/// Quotes text so it can be safely embedded in a shell command.
+///
+/// By default wraps the value in single quotes, escaping embedded single
+/// quotes as `'\''`. An optional `style="double"` argument instead wraps it in
+/// double quotes, backslash-escaping `"`, `` ` ``, `$`, and `\`.
+///
+/// # Example
+///
+/// ```
+/// use tera::{Context, Tera};
+/// use tera_text_filters::quote;
+///
+/// let mut ctx = Context::new();
+/// ctx.insert("i", "it's text");
+///
+/// let mut tera = Tera::default();
+/// tera.register_filter("quote", quote);
+///
+/// let i = "{{ i | quote }}";
+/// let rendered = tera.render_str(i, &ctx).unwrap();
+/// assert_eq!(rendered, r#"'it'\''s text'"#);
+/// ```
+pub fn quote<S: BuildHasher>(value: &Value, args: &HashMap<String, Value, S>) -> Result<Value> {
+    let s = try_get_value!("quote", "value", String, value);
+    let style = match args.get("style") {
+        Some(val) => try_get_value!("quote", "style", String, val),
+        None => "single".to_string(),
+    };
+
+    let quoted = match style.as_str() {
+        "single" => format!("'{}'", s.replace('\'', r"'\''")),
+        "double" => {
+            let escaped: String = s
+                .chars()
+                .flat_map(|c| match c {
+                    '"' | '`' | '$' | '\\' => vec!['\\', c],
+                    other => vec![other],
+                })
+                .collect();
+            format!("\"{}\"", escaped)
+        }
+        other => {
+            return Err(Error::msg(format!(
+                "Filter `quote` received an unknown style `{}`",
+                other
+            )))
+        }
+    };
+
+    Ok(to_value(&quoted).unwrap())
+}
+
+/// Randomly upper/lower-cases each letter, leaving non-letter characters untouched.
+///
+/// An optional `seed` argument selects a seeded, reproducible RNG; without it a
+/// fresh thread-local RNG is used.
+///
+/// # Example
+///
+/// ```
+/// use tera::{Context, Tera};
+/// use tera_text_filters::random_case;
+///
+/// let mut ctx = Context::new();
+/// ctx.insert("i", "some text");
+///
+/// let mut tera = Tera::default();
+/// tera.register_filter("random_case", random_case);
+///
+/// let i = "{{ i | random_case(seed=42) }}";
+/// let first = tera.render_str(i, &ctx).unwrap();
+/// let second = tera.render_str(i, &ctx).unwrap();
+/// assert_eq!(first.to_lowercase(), "some text");
+/// assert_eq!(first, second);
+/// ```
+pub fn random_case<S: BuildHasher>(
+    value: &Value,
+    args: &HashMap<String, Value, S>,
+) -> Result<Value> {
+    let s = try_get_value!("random_case", "value", String, value);
+    let seed = match args.get("seed") {
+        Some(val) => Some(try_get_value!("random_case", "seed", u64, val)),
+        None => None,
+    };
+
+    let mut rng: Box<dyn RngCore> = match seed {
+        Some(seed) => Box::new(StdRng::seed_from_u64(seed)),
+        None => Box::new(rand::thread_rng()),
+    };
+
+    let randomized: String = s
+        .chars()
+        .map(|c| {
+            if !c.is_alphabetic() {
+                return c;
+            }
+            if rng.gen_bool(0.5) {
+                c.to_uppercase().next().unwrap_or(c)
+            } else {
+                c.to_lowercase().next().unwrap_or(c)
+            }
+        })
+        .collect();
+
+    Ok(to_value(&randomized).unwrap())
+}
+
+/// Converts text into SHOUTY-KEBAB-CASE.
+///
+/// # Example
+///
+/// ```
+/// use tera::{Context, Tera};
+/// use tera_text_filters::shouty_kebab_case;
+///
+/// let mut ctx = Context::new();
+/// ctx.insert("i", "some text");
+///
+/// let mut tera = Tera::default();
+/// tera.register_filter("shouty_kebab_case", shouty_kebab_case);
+///
+/// let i = "{{ i | shouty_kebab_case }}";
+/// let rendered = tera.render_str(i, &ctx).unwrap();
+/// assert_eq!(rendered, "SOME-TEXT");
+/// ```
+pub fn shouty_kebab_case<S: BuildHasher>(
+    value: &Value,
+    _: &HashMap<String, Value, S>,
+) -> Result<Value> {
+    let s = try_get_value!("shouty_kebab_case", "value", String, value);
+    let mut out = String::new();
+    write!(out, "{}", AsShoutyKebabCase(&s)).unwrap();
+    Ok(to_value(&out).unwrap())
+}
+
+/// Converts text into SHOUTY_SNAKE_CASE.
+///
+/// # Example
+///
+/// ```
+/// use tera::{Context, Tera};
+/// use tera_text_filters::shouty_snake_case;
+///
+/// let mut ctx = Context::new();
+/// ctx.insert("i", "some text");
+///
+/// let mut tera = Tera::default();
+/// tera.register_filter("shouty_snake_case", shouty_snake_case);
+///
+/// let i = "{{ i | shouty_snake_case }}";
+/// let rendered = tera.render_str(i, &ctx).unwrap();
+/// assert_eq!(rendered, "SOME_TEXT");
+/// ```
+pub fn shouty_snake_case<S: BuildHasher>(
+    value: &Value,
+    _: &HashMap<String, Value, S>,
+) -> Result<Value> {
+    let s = try_get_value!("shouty_snake_case", "value", String, value);
+    let mut out = String::new();
+    write!(out, "{}", AsShoutySnakeCase(&s)).unwrap();
+    Ok(to_value(&out).unwrap())
 }
 
 /// Converts text into snake_case.
@@ -127,7 +438,9 @@ pub fn mixed_case<S: BuildHasher>(value: &Value, _: &HashMap<String, Value, S>)
 /// ```
 pub fn snake_case<S: BuildHasher>(value: &Value, _: &HashMap<String, Value, S>) -> Result<Value> {
     let s = try_get_value!("snake_case", "value", String, value);
-    Ok(to_value(&s.to_snake_case()).unwrap())
+    let mut out = String::new();
+    write!(out, "{}", AsSnakeCase(&s)).unwrap();
+    Ok(to_value(&out).unwrap())
 }
 
 /// Converts text into Title Case.
@@ -150,7 +463,69 @@ pub fn snake_case<S: BuildHasher>(value: &Value, _: &HashMap<String, Value, S>)
 /// ```
 pub fn title_case<S: BuildHasher>(value: &Value, _: &HashMap<String, Value, S>) -> Result<Value> {
     let s = try_get_value!("title_case", "value", String, value);
-    Ok(to_value(&s.to_title_case()).unwrap())
+    let mut out = String::new();
+    write!(out, "{}", AsTitleCase(&s)).unwrap();
+    Ok(to_value(&out).unwrap())
+}
+
+/// Flips the case of every alphabetic character, leaving everything else untouched.
+///
+/// # Example
+///
+/// ```
+/// use tera::{Context, Tera};
+/// use tera_text_filters::toggle_case;
+///
+/// let mut ctx = Context::new();
+/// ctx.insert("i", "Some Text");
+///
+/// let mut tera = Tera::default();
+/// tera.register_filter("toggle_case", toggle_case);
+///
+/// let i = "{{ i | toggle_case }}";
+/// let rendered = tera.render_str(i, &ctx).unwrap();
+/// assert_eq!(rendered, "sOME tEXT");
+/// ```
+pub fn toggle_case<S: BuildHasher>(value: &Value, _: &HashMap<String, Value, S>) -> Result<Value> {
+    let s = try_get_value!("toggle_case", "value", String, value);
+    let toggled: String = s
+        .chars()
+        .map(|c| {
+            if c.is_uppercase() {
+                c.to_lowercase().next().unwrap_or(c)
+            } else if c.is_lowercase() {
+                c.to_uppercase().next().unwrap_or(c)
+            } else {
+                c
+            }
+        })
+        .collect();
+    Ok(to_value(&toggled).unwrap())
+}
+
+/// Converts text into Train-Case.
+///
+/// # Example
+///
+/// ```
+/// use tera::{Context, Tera};
+/// use tera_text_filters::train_case;
+///
+/// let mut ctx = Context::new();
+/// ctx.insert("i", "some text");
+///
+/// let mut tera = Tera::default();
+/// tera.register_filter("train_case", train_case);
+///
+/// let i = "{{ i | train_case }}";
+/// let rendered = tera.render_str(i, &ctx).unwrap();
+/// assert_eq!(rendered, "Some-Text");
+/// ```
+pub fn train_case<S: BuildHasher>(value: &Value, _: &HashMap<String, Value, S>) -> Result<Value> {
+    let s = try_get_value!("train_case", "value", String, value);
+    let mut out = String::new();
+    write!(out, "{}", AsTrainCase(&s)).unwrap();
+    Ok(to_value(&out).unwrap())
 }
 
 /// Converts text into UPPERCASE.
@@ -175,3 +550,52 @@ pub fn upper_case<S: BuildHasher>(value: &Value, _: &HashMap<String, Value, S>)
     let s = try_get_value!("upper_case", "value", String, value);
     Ok(to_value(&s.to_uppercase()).unwrap())
 }
+
+/// Splits `input` into words on delimiters, case transitions, letter/digit
+/// boundaries, and acronym boundaries, so that identifiers like `"HTTPServer"`
+/// or `"parseJSON2File"` segment the way a human would expect.
+///
+/// Used by [`convert_case`] when its `split` argument is set, to work around
+/// heck's naive splitting of consecutive uppercase runs.
+fn segment_words(input: &str) -> Vec<String> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut prev: Option<char> = None;
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' || c == '-' || c.is_whitespace() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            prev = None;
+            continue;
+        }
+
+        let is_boundary = match prev {
+            None => false,
+            Some(p) => {
+                let lower_to_upper = p.is_lowercase() && c.is_uppercase();
+                let letter_digit_transition = (p.is_alphabetic() && c.is_numeric())
+                    || (p.is_numeric() && c.is_alphabetic());
+                let acronym_boundary = p.is_uppercase()
+                    && c.is_uppercase()
+                    && chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+                lower_to_upper || letter_digit_transition || acronym_boundary
+            }
+        };
+
+        if is_boundary && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+
+        current.push(c);
+        prev = Some(c);
+    }
+
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}